@@ -0,0 +1,90 @@
+use std::fs;
+
+use tauri::{Manager, State};
+use tauri_plugin_notification::NotificationExt;
+
+use crate::tray::AgentState;
+use crate::workspace::Workspace;
+
+/// Simple connectivity check used by the frontend on startup.
+#[tauri::command]
+pub fn ping() -> String {
+    "pong".into()
+}
+
+/// Advances the agent loop by one step and returns a status string for the UI.
+///
+/// This is a stub entry point for the native agent driver; the actual step
+/// logic (model call, tool execution, etc.) will be threaded in as that
+/// machinery lands.
+#[tauri::command]
+pub async fn run_agent_step(
+    agent_state: State<'_, AgentState>,
+    task_id: String,
+) -> Result<String, String> {
+    if agent_state.is_paused() {
+        return Err("agent is paused".into());
+    }
+    Ok(format!("step completed for task {task_id}"))
+}
+
+/// Cancels an in-flight agent task by id.
+#[tauri::command]
+pub async fn cancel_task(task_id: String) -> Result<(), String> {
+    let _ = task_id;
+    Ok(())
+}
+
+/// Reads a file from the workspace and returns its contents as a UTF-8 string.
+///
+/// `path` is relative to the workspace root and is validated by
+/// [`Workspace::resolve`] before anything touches disk.
+#[tauri::command]
+pub fn read_workspace_file(workspace: State<Workspace>, path: String) -> Result<String, String> {
+    let resolved = workspace.resolve(&path).map_err(|e| e.to_string())?;
+    fs::read_to_string(resolved).map_err(|e| e.to_string())
+}
+
+/// Writes `contents` to a file in the workspace, validated the same way as
+/// [`read_workspace_file`]. Missing intermediate directories under the
+/// workspace root are created as needed.
+#[tauri::command]
+pub fn write_workspace_file(
+    workspace: State<Workspace>,
+    path: String,
+    contents: String,
+) -> Result<(), String> {
+    let resolved = workspace.resolve(&path).map_err(|e| e.to_string())?;
+    if let Some(parent) = resolved.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    fs::write(resolved, contents).map_err(|e| e.to_string())
+}
+
+/// Hides the main window. Intended to be wired to an `Esc` `keydown`
+/// listener in the webview frontend, so Esc dismisses the window without
+/// being registered as a global OS shortcut (which would steal Esc from
+/// every other application).
+///
+/// No frontend exists in this tree yet, so that `keydown` wiring is not
+/// done here — this command is the native half of it, ready for the
+/// frontend to call once it lands.
+#[tauri::command]
+pub fn dismiss_window(app: tauri::AppHandle) -> Result<(), String> {
+    if let Some(window) = app.get_webview_window("main") {
+        window.hide().map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Posts a brief, auto-expiring OS notification, e.g. when a task finishes,
+/// errors, or needs user input while the window is backgrounded.
+#[tauri::command]
+pub fn notify(app: tauri::AppHandle, title: String, body: String) -> Result<(), String> {
+    app.notification()
+        .builder()
+        .title(title)
+        .body(body)
+        .show()
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_global_shortcut::{Code, GlobalShortcutExt, Modifiers, Shortcut, ShortcutState};
+
+/// User-overridable chord used to summon the agent window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HotkeyConfig {
+    pub summon: String,
+}
+
+impl Default for HotkeyConfig {
+    fn default() -> Self {
+        Self {
+            summon: "Ctrl+Shift+Q".into(),
+        }
+    }
+}
+
+impl HotkeyConfig {
+    /// Loads the hotkey config from `<app config dir>/hotkey.json`, falling
+    /// back to the default chord if it's missing or invalid.
+    pub fn load(app: &AppHandle) -> Self {
+        app.path()
+            .app_config_dir()
+            .ok()
+            .and_then(|dir| std::fs::read_to_string(dir.join("hotkey.json")).ok())
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default()
+    }
+}
+
+/// Registers the summon chord (default `Ctrl+Shift+Q`) so the agent window
+/// can be shown and focused from anywhere without it staying on screen like
+/// a normal foreground app.
+///
+/// Dismissing the window on `Esc` is intentionally *not* done here: a
+/// no-modifier global shortcut would grab Escape system-wide and take it
+/// away from every other application. That belongs at the window/webview
+/// level instead — [`crate::commands::dismiss_window`] is the native half
+/// of it, but there's no frontend in this tree yet to attach the `keydown`
+/// listener that calls it, so Esc-to-dismiss is not wired end-to-end until
+/// that frontend exists.
+pub fn register(app: &AppHandle, config: &HotkeyConfig) -> tauri::Result<()> {
+    let summon: Shortcut = config.summon.parse().unwrap_or_else(|_| {
+        Shortcut::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::KeyQ)
+    });
+
+    app.global_shortcut()
+        .on_shortcut(summon, |app, _shortcut, event| {
+            if event.state() == ShortcutState::Pressed {
+                if let Some(window) = app.get_webview_window("main") {
+                    let _ = window.show();
+                    let _ = window.set_focus();
+                }
+            }
+        })?;
+
+    Ok(())
+}
@@ -1,8 +1,53 @@
-use tauri_plugin_fs;
+#![cfg_attr(all(not(debug_assertions), target_os = "windows"), windows_subsystem = "windows")]
+
+use tauri::{Manager, WindowEvent};
+use tauri_plugin_notification::NotificationExt;
+
+mod commands;
+mod hotkey;
+mod tray;
+mod workspace;
+
+use hotkey::HotkeyConfig;
+use tray::AgentState;
+use workspace::Workspace;
 
 fn main() {
+    // No tauri_plugin_fs here: raw, unscoped fs access would let a
+    // frontend or agent-generated call bypass the workspace sandbox below.
+    // All disk access goes through the vetted commands in `commands`.
     tauri::Builder::default()
-        .plugin(tauri_plugin_fs::init())
+        .plugin(tauri_plugin_notification::init())
+        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+        .setup(|app| {
+            app.notification().request_permission()?;
+
+            let workspace_root = app.path().app_data_dir()?.join("workspace");
+            std::fs::create_dir_all(&workspace_root)?;
+            app.manage(Workspace::new(workspace_root));
+            app.manage(AgentState::new());
+
+            tray::build(app.handle())?;
+            hotkey::register(app.handle(), &HotkeyConfig::load(app.handle()))?;
+
+            Ok(())
+        })
+        .on_window_event(|window, event| {
+            if let WindowEvent::CloseRequested { api, .. } = event {
+                // Minimize to tray instead of quitting so the agent loop keeps running.
+                window.hide().ok();
+                api.prevent_close();
+            }
+        })
+        .invoke_handler(tauri::generate_handler![
+            commands::ping,
+            commands::run_agent_step,
+            commands::cancel_task,
+            commands::read_workspace_file,
+            commands::write_workspace_file,
+            commands::dismiss_window,
+            commands::notify,
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
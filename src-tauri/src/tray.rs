@@ -0,0 +1,89 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use tauri::image::Image;
+use tauri::menu::{CheckMenuItem, Menu, MenuItem, PredefinedMenuItem};
+use tauri::tray::TrayIconBuilder;
+use tauri::{AppHandle, Manager};
+
+/// Bundled tray glyph, embedded so the tray always has an icon regardless of
+/// whether a bundle/window icon is configured elsewhere.
+static TRAY_ICON: &[u8] = include_bytes!("../icons/tray-icon.png");
+
+/// Tracks whether the agent loop is paused, toggled from the tray menu.
+pub struct AgentState {
+    paused: AtomicBool,
+}
+
+impl Default for AgentState {
+    fn default() -> Self {
+        Self {
+            paused: AtomicBool::new(false),
+        }
+    }
+}
+
+impl AgentState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    fn toggle(&self) -> bool {
+        let paused = !self.paused.load(Ordering::SeqCst);
+        self.paused.store(paused, Ordering::SeqCst);
+        paused
+    }
+}
+
+/// Builds the system tray icon and menu, letting the window be minimized to
+/// tray instead of the app fully quitting when it's closed.
+pub fn build(app: &AppHandle) -> tauri::Result<()> {
+    let show = MenuItem::with_id(app, "show", "Show", true, None::<&str>)?;
+    let hide = MenuItem::with_id(app, "hide", "Hide", true, None::<&str>)?;
+    let pause = CheckMenuItem::with_id(app, "pause_agent", "Pause agent", true, false, None::<&str>)?;
+    let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+    let menu = Menu::with_items(
+        app,
+        &[&show, &hide, &PredefinedMenuItem::separator(app)?, &pause, &quit],
+    )?;
+
+    // Share the check item with the menu-event handler so toggling the
+    // agent also flips the checkbox, giving the pause state a visible
+    // affordance instead of a static "Pause agent" label.
+    app.manage(pause.clone());
+
+    let icon = Image::from_bytes(TRAY_ICON)?;
+
+    TrayIconBuilder::new()
+        .menu(&menu)
+        .icon(icon)
+        .on_menu_event(|app, event| match event.id.as_ref() {
+            "show" => {
+                if let Some(window) = app.get_webview_window("main") {
+                    let _ = window.show();
+                    let _ = window.set_focus();
+                }
+            }
+            "hide" => {
+                if let Some(window) = app.get_webview_window("main") {
+                    let _ = window.hide();
+                }
+            }
+            "pause_agent" => {
+                if let Some(state) = app.try_state::<AgentState>() {
+                    let paused = state.toggle();
+                    if let Some(item) = app.try_state::<CheckMenuItem<tauri::Wry>>() {
+                        let _ = item.set_checked(paused);
+                    }
+                }
+            }
+            "quit" => app.exit(0),
+            _ => {}
+        })
+        .build(app)?;
+
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Guards filesystem access to a single allowlisted workspace root.
+///
+/// Agent-generated file operations are untrusted input: a model could emit a
+/// path containing `..`, an absolute path outside the project, or a symlink
+/// that resolves outside the sandbox. Every mutation should go through
+/// [`Workspace::resolve`] rather than touching the fs plugin directly.
+pub struct Workspace {
+    root: PathBuf,
+}
+
+#[derive(Debug)]
+pub enum WorkspaceError {
+    /// The path contained a `..` component or otherwise escaped the root.
+    Traversal,
+    /// The path resolved to a location outside the workspace root.
+    OutsideSandbox,
+    /// The path exists but is, or passes through, a symlink that escapes the sandbox.
+    SymlinkEscape,
+    Io(String),
+}
+
+impl fmt::Display for WorkspaceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorkspaceError::Traversal => write!(f, "path traversal is not allowed"),
+            WorkspaceError::OutsideSandbox => write!(f, "path is outside the workspace"),
+            WorkspaceError::SymlinkEscape => write!(f, "path escapes the workspace via a symlink"),
+            WorkspaceError::Io(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl std::error::Error for WorkspaceError {}
+
+impl Workspace {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Resolves `requested` against the workspace root, rejecting anything
+    /// that would place the final path outside of it.
+    ///
+    /// The root and the nearest existing ancestor of the target are
+    /// canonicalized so that both `..` traversal and symlink escapes are
+    /// caught by the same containment check, even when `requested` names a
+    /// file or directory (and intermediate parents) that don't exist yet.
+    pub fn resolve(&self, requested: &str) -> Result<PathBuf, WorkspaceError> {
+        let requested = Path::new(requested);
+
+        if requested.is_absolute() {
+            return Err(WorkspaceError::OutsideSandbox);
+        }
+        if requested
+            .components()
+            .any(|c| matches!(c, std::path::Component::ParentDir))
+        {
+            return Err(WorkspaceError::Traversal);
+        }
+
+        let root = self
+            .root
+            .canonicalize()
+            .map_err(|e| WorkspaceError::Io(e.to_string()))?;
+        let candidate = root.join(requested);
+
+        let mut ancestor = candidate.as_path();
+        let checked_ancestor = loop {
+            if ancestor.exists() {
+                break ancestor
+                    .canonicalize()
+                    .map_err(|e| WorkspaceError::Io(e.to_string()))?;
+            }
+            ancestor = ancestor.parent().ok_or(WorkspaceError::OutsideSandbox)?;
+        };
+
+        if !checked_ancestor.starts_with(&root) {
+            return Err(WorkspaceError::SymlinkEscape);
+        }
+
+        if candidate.exists() {
+            Ok(checked_ancestor)
+        } else {
+            Ok(candidate)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    fn temp_root() -> PathBuf {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let id = COUNTER.fetch_add(1, Ordering::SeqCst);
+        let dir = std::env::temp_dir().join(format!(
+            "auto-agent-workspace-test-{}-{id}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn rejects_parent_dir_traversal() {
+        let workspace = Workspace::new(temp_root());
+        let err = workspace.resolve("../escape.txt").unwrap_err();
+        assert!(matches!(err, WorkspaceError::Traversal));
+    }
+
+    #[test]
+    fn rejects_absolute_paths() {
+        let workspace = Workspace::new(temp_root());
+        let err = workspace.resolve("/etc/passwd").unwrap_err();
+        assert!(matches!(err, WorkspaceError::OutsideSandbox));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn rejects_symlink_escape() {
+        let root = temp_root();
+        let outside = temp_root();
+        std::os::unix::fs::symlink(&outside, root.join("escape")).unwrap();
+
+        let workspace = Workspace::new(root);
+        let err = workspace.resolve("escape/file.txt").unwrap_err();
+        assert!(matches!(err, WorkspaceError::SymlinkEscape));
+    }
+
+    #[test]
+    fn resolves_not_yet_created_nested_path() {
+        let root = temp_root();
+        let workspace = Workspace::new(root.clone());
+
+        let resolved = workspace.resolve("notes/todo.txt").unwrap();
+
+        assert_eq!(resolved, root.canonicalize().unwrap().join("notes/todo.txt"));
+    }
+
+    #[test]
+    fn resolves_existing_nested_path() {
+        let root = temp_root();
+        std::fs::create_dir_all(root.join("nested")).unwrap();
+        std::fs::write(root.join("nested/file.txt"), b"hi").unwrap();
+        let workspace = Workspace::new(root.clone());
+
+        let resolved = workspace.resolve("nested/file.txt").unwrap();
+
+        assert_eq!(
+            resolved,
+            root.canonicalize().unwrap().join("nested/file.txt")
+        );
+    }
+}